@@ -1261,6 +1261,10 @@ impl BlockChainClient for Client {
 		self.state_at(id).and_then(|s| s.balance(address).ok())
 	}
 
+	fn balances(&self, addresses: Vec<Address>, id: BlockId) -> Option<Vec<U256>> {
+		self.state_at(id).and_then(|s| addresses.iter().map(|a| s.balance(a).ok()).collect())
+	}
+
 	fn storage_at(&self, address: &Address, position: &H256, id: BlockId) -> Option<H256> {
 		self.state_at(id).and_then(|s| s.storage_at(address, position).ok())
 	}
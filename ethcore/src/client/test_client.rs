@@ -441,6 +441,15 @@ impl BlockChainClient for TestBlockChainClient {
 		}
 	}
 
+	fn balances(&self, addresses: Vec<Address>, id: BlockId) -> Option<Vec<U256>> {
+		if let BlockId::Latest = id {
+			let balances = self.balances.read();
+			Some(addresses.iter().map(|a| balances.get(a).cloned().unwrap_or_else(U256::zero)).collect())
+		} else {
+			None
+		}
+	}
+
 	fn latest_balance(&self, address: &Address) -> U256 {
 		self.balance(address, BlockId::Latest).unwrap()
 	}
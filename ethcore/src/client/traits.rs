@@ -99,6 +99,13 @@ pub trait BlockChainClient : Sync + Send {
 	/// Returns None if and only if the block's root hash has been pruned from the DB.
 	fn balance(&self, address: &Address, id: BlockId) -> Option<U256>;
 
+	/// Get balances of many addresses at the given block's state, opening the state only once.
+	///
+	/// Balances are returned in the order of the given addresses.
+	/// Returns None if the block's root hash has been pruned from the DB, or if reading
+	/// any one of the balances fails; no partial results are returned.
+	fn balances(&self, addresses: Vec<Address>, id: BlockId) -> Option<Vec<U256>>;
+
 	/// Get address balance at the latest block's state.
 	fn latest_balance(&self, address: &Address) -> U256 {
 		self.balance(address, BlockId::Latest)
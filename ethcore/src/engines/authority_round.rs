@@ -16,7 +16,6 @@
 
 //! A blockchain engine that supports a non-instant BFT proof-of-authority.
 
-use std::sync::atomic::{AtomicUsize, AtomicBool, Ordering as AtomicOrdering};
 use std::sync::Weak;
 use std::time::{UNIX_EPOCH, Duration};
 use util::*;
@@ -64,7 +63,10 @@ impl From<ethjson::spec::AuthorityRoundParams> for AuthorityRoundParams {
 	fn from(p: ethjson::spec::AuthorityRoundParams) -> Self {
 		AuthorityRoundParams {
 			gas_limit_bound_divisor: p.gas_limit_bound_divisor.into(),
-			step_duration: Duration::from_secs(p.step_duration.into()),
+			step_duration: match p.step_duration_ms {
+				Some(millis) => Duration::from_millis(millis.into()),
+				None => Duration::from_secs(p.step_duration.map_or(0, Into::into)),
+			},
 			validators: new_validator_set(p.validators),
 			block_reward: p.block_reward.map_or_else(U256::zero, Into::into),
 			registrar: p.registrar.map_or_else(Address::new, Into::into),
@@ -80,35 +82,40 @@ impl From<ethjson::spec::AuthorityRoundParams> for AuthorityRoundParams {
 #[derive(Debug)]
 struct Step {
 	calibrate: bool, // whether calibration is enabled.
-	inner: AtomicUsize,
+	inner: RwLock<u64>,
 	duration: Duration,
 }
 
 impl Step {
-	fn load(&self) -> usize { self.inner.load(AtomicOrdering::SeqCst) }
+	fn load(&self) -> u64 { *self.inner.read() }
 	fn duration_remaining(&self) -> Duration {
 		let now = unix_now();
-		let step_end = self.duration * (self.load() as u32 + 1);
-		if step_end > now {
-			step_end - now
-		} else {
-			Duration::from_secs(0)
+		let step_end = self.load().checked_add(1)
+			.and_then(|next| next.checked_mul(self.duration.as_millis()))
+			.map(Duration::from_millis);
+		match step_end {
+			Some(step_end) if step_end > now => step_end - now,
+			Some(_) => Duration::from_secs(0),
+			None => {
+				warn!(target: "engine", "Step {} is too large to compute its end time.", self.load());
+				self.duration
+			},
 		}
 	}
 	fn increment(&self) {
-		self.inner.fetch_add(1, AtomicOrdering::SeqCst);
+		*self.inner.write() += 1;
 	}
 	fn calibrate(&self) {
 		if self.calibrate {
-			let new_step = unix_now().as_secs() / self.duration.as_secs();
-			self.inner.store(new_step as usize, AtomicOrdering::SeqCst);
+			let new_step = unix_now().as_millis() / self.duration.as_millis();
+			*self.inner.write() = new_step;
 		}
 	}
-	fn is_future(&self, given: usize) -> bool {
-		if given > self.load() + 1 {
+	fn is_future(&self, given: u64) -> bool {
+		if given > self.load().saturating_add(1) {
 			// Make absolutely sure that the given step is correct.
 			self.calibrate();
-			given > self.load() + 1
+			given > self.load().saturating_add(1)
 		} else {
 			false
 		}
@@ -124,7 +131,7 @@ pub struct AuthorityRound {
 	builtins: BTreeMap<Address, Builtin>,
 	transition_service: IoService<()>,
 	step: Arc<Step>,
-	proposed: AtomicBool,
+	proposed: RwLock<Option<(u64, H256)>>,
 	client: RwLock<Option<Weak<EngineClient>>>,
 	signer: EngineSigner,
 	validators: Box<ValidatorSet>,
@@ -149,7 +156,7 @@ impl super::EpochVerifier for EpochVerifier {
 	}
 }
 
-fn header_step(header: &Header) -> Result<usize, ::rlp::DecoderError> {
+fn header_step(header: &Header) -> Result<u64, ::rlp::DecoderError> {
 	UntrustedRlp::new(&header.seal().get(0).expect("was either checked with verify_block_basic or is genesis; has 2 fields; qed (Make sure the spec file has a correct genesis seal)")).as_val()
 }
 
@@ -193,8 +200,11 @@ impl AsMillis for Duration {
 impl AuthorityRound {
 	/// Create a new instance of AuthorityRound engine.
 	pub fn new(params: CommonParams, our_params: AuthorityRoundParams, builtins: BTreeMap<Address, Builtin>) -> Result<Arc<Self>, Error> {
+		if our_params.step_duration.as_millis() == 0 {
+			Err(EngineError::InvalidParams("step duration must be at least one millisecond".into()))?;
+		}
 		let should_timeout = our_params.start_step.is_none();
-		let initial_step = our_params.start_step.unwrap_or_else(|| (unix_now().as_secs() / our_params.step_duration.as_secs())) as usize;
+		let initial_step = our_params.start_step.unwrap_or_else(|| (unix_now().as_millis() / our_params.step_duration.as_millis()));
 		let engine = Arc::new(
 			AuthorityRound {
 				params: params,
@@ -204,11 +214,11 @@ impl AuthorityRound {
 				builtins: builtins,
 				transition_service: IoService::<()>::start()?,
 				step: Arc::new(Step {
-					inner: AtomicUsize::new(initial_step),
+					inner: RwLock::new(initial_step),
 					calibrate: our_params.start_step.is_none(),
 					duration: our_params.step_duration,
 				}),
-				proposed: AtomicBool::new(false),
+				proposed: RwLock::new(None),
 				client: RwLock::new(None),
 				signer: Default::default(),
 				validators: our_params.validators,
@@ -224,11 +234,11 @@ impl AuthorityRound {
 		Ok(engine)
 	}
 
-	fn step_proposer(&self, bh: &H256, step: usize) -> Address {
+	fn step_proposer(&self, bh: &H256, step: u64) -> Address {
 		self.validators.get(bh, step)
 	}
 
-	fn is_step_proposer(&self, bh: &H256, step: usize, address: &Address) -> bool {
+	fn is_step_proposer(&self, bh: &H256, step: u64, address: &Address) -> bool {
 		self.step_proposer(bh, step) == *address
 	}
 }
@@ -280,7 +290,7 @@ impl Engine for AuthorityRound {
 
 	fn step(&self) {
 		self.step.increment();
-		self.proposed.store(false, AtomicOrdering::SeqCst);
+		*self.proposed.write() = None;
 		if let Some(ref weak) = *self.client.read() {
 			if let Some(c) = weak.upgrade() {
 				c.update_sealing();
@@ -325,13 +335,14 @@ impl Engine for AuthorityRound {
 	/// This operation is synchronous and may (quite reasonably) not be available, in which `false` will
 	/// be returned.
 	fn generate_seal(&self, block: &ExecutedBlock) -> Seal {
-		if self.proposed.load(AtomicOrdering::SeqCst) { return Seal::None; }
 		let header = block.header();
 		let step = self.step.load();
+		// Only one proposal per parent in each step; a new parent may replace an orphaned proposal.
+		if *self.proposed.read() == Some((step, *header.parent_hash())) { return Seal::None; }
 		if self.is_step_proposer(header.parent_hash(), step, header.author()) {
 			if let Ok(signature) = self.signer.sign(header.bare_hash()) {
 				trace!(target: "engine", "generate_seal: Issuing a block for step {}.", step);
-				self.proposed.store(true, AtomicOrdering::SeqCst);
+				*self.proposed.write() = Some((step, *header.parent_hash()));
 				return Seal::Regular(vec![encode(&step).to_vec(), encode(&(&H520::from(signature) as &[u8])).to_vec()]);
 			} else {
 				warn!(target: "engine", "generate_seal: FAIL: Accounts secret key unavailable.");
@@ -394,7 +405,7 @@ impl Engine for AuthorityRound {
 			Err(EngineError::DoubleVote(header.author().clone()))?;
 		}
 		// Report skipped primaries.
-		if step > parent_step + 1 {
+		if step > parent_step.saturating_add(1) {
 			for s in parent_step + 1..step {
 				let skipped_primary = self.step_proposer(&parent.hash(), s);
 				trace!(target: "engine", "Author {} did not build his block on top of the intermediate designated primary {}.", header.author(), skipped_primary);
@@ -464,6 +475,10 @@ impl Engine for AuthorityRound {
 		self.signer.sign(hash).map_err(Into::into)
 	}
 
+	fn stop(&self) {
+		self.transition_service.stop()
+	}
+
 	fn snapshot_components(&self) -> Option<Box<::snapshot::SnapshotComponents>> {
 		Some(Box::new(::snapshot::PoaSnapshot))
 	}
@@ -472,6 +487,7 @@ impl Engine for AuthorityRound {
 #[cfg(test)]
 mod tests {
 	use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+	use std::time::Duration;
 	use util::*;
 	use header::Header;
 	use error::{Error, BlockError};
@@ -482,7 +498,7 @@ mod tests {
 	use spec::Spec;
 	use engines::{Seal, Engine};
 	use engines::validator_set::TestSet;
-	use super::{AuthorityRoundParams, AuthorityRound};
+	use super::{AuthorityRoundParams, AuthorityRound, Step, header_step};
 
 	#[test]
 	fn has_valid_metadata() {
@@ -555,6 +571,39 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn proposes_on_new_parent_within_step() {
+		let tap = Arc::new(AccountProvider::transient_provider());
+		let addr1 = tap.insert_account("1".sha3().into(), "1").unwrap();
+
+		let spec = Spec::new_test_round();
+		let engine = &*spec.engine;
+		let genesis_header = spec.genesis_header();
+		let mut other_parent = genesis_header.clone();
+		other_parent.set_extra_data(b"other".to_vec());
+		let db1 = spec.ensure_db_good(get_temp_state_db(), &Default::default()).unwrap();
+		let db2 = spec.ensure_db_good(get_temp_state_db(), &Default::default()).unwrap();
+		let b1 = OpenBlock::new(engine, Default::default(), false, db1, &genesis_header, Arc::new(vec![genesis_header.hash()]), addr1, (3141562.into(), 31415620.into()), vec![]).unwrap();
+		let b1 = b1.close_and_lock();
+		let b2 = OpenBlock::new(engine, Default::default(), false, db2, &other_parent, Arc::new(vec![other_parent.hash()]), addr1, (3141562.into(), 31415620.into()), vec![]).unwrap();
+		let b2 = b2.close_and_lock();
+
+		// Spec starts with step 2, which belongs to addr1.
+		engine.set_signer(tap, addr1, "1".into());
+		match engine.generate_seal(b1.block()) {
+			Seal::Regular(seal) => assert!(b1.clone().try_seal(engine, seal).is_ok()),
+			_ => panic!("addr1 should propose for step 2"),
+		}
+		// Second proposal on the same parent is forbidden.
+		assert!(engine.generate_seal(b1.block()) == Seal::None);
+		// Proposal on a different parent within the same step is allowed.
+		match engine.generate_seal(b2.block()) {
+			Seal::Regular(seal) => assert!(b2.clone().try_seal(engine, seal).is_ok()),
+			_ => panic!("addr1 should propose on top of a new parent"),
+		}
+		assert!(engine.generate_seal(b2.block()) == Seal::None);
+	}
+
 	#[test]
 	fn proposer_switching() {
 		let tap = AccountProvider::transient_provider();
@@ -657,4 +706,58 @@ mod tests {
 		assert!(aura.verify_block_family(&header, &parent_header, None).is_ok());
 		assert_eq!(last_benign.load(AtomicOrdering::SeqCst), 1);
 	}
+
+	#[test]
+	fn handles_large_steps() {
+		let large = u32::max_value() as u64 + 1;
+		let step = Step { calibrate: false, inner: RwLock::new(large), duration: Duration::from_secs(1) };
+		assert!(step.duration_remaining() > Duration::from_secs(1));
+		assert!(step.is_future(large + 2));
+
+		// The end of the last step is not representable; wait a full step instead.
+		let step = Step { calibrate: false, inner: RwLock::new(u64::max_value()), duration: Duration::from_secs(1) };
+		assert_eq!(step.duration_remaining(), Duration::from_secs(1));
+		assert!(!step.is_future(u64::max_value()));
+
+		let mut header: Header = Header::default();
+		header.set_seal(vec![encode(&large).to_vec()]);
+		assert_eq!(header_step(&header).unwrap(), large);
+	}
+
+	#[test]
+	fn rejects_zero_step_duration() {
+		let params = AuthorityRoundParams {
+			gas_limit_bound_divisor: U256::from_str("400").unwrap(),
+			step_duration: Duration::from_secs(0),
+			block_reward: Default::default(),
+			registrar: Default::default(),
+			start_step: None,
+			validators: Box::new(TestSet::new(Default::default(), Default::default())),
+			validate_score_transition: 0,
+			validate_step_transition: 0,
+			eip155_transition: 0,
+		};
+		assert!(AuthorityRound::new(Default::default(), params, Default::default()).is_err());
+	}
+
+	#[test]
+	fn stops_step_timer() {
+		let params = AuthorityRoundParams {
+			gas_limit_bound_divisor: U256::from_str("400").unwrap(),
+			step_duration: Duration::from_millis(20),
+			block_reward: Default::default(),
+			registrar: Default::default(),
+			start_step: None,
+			validators: Box::new(TestSet::new(Default::default(), Default::default())),
+			validate_score_transition: 0,
+			validate_step_transition: 0,
+			eip155_transition: 0,
+		};
+		let aura = AuthorityRound::new(Default::default(), params, Default::default()).unwrap();
+
+		aura.stop();
+		let step = aura.step.load();
+		::std::thread::sleep(Duration::from_millis(60));
+		assert_eq!(aura.step.load(), step);
+	}
 }
@@ -72,6 +72,8 @@ pub enum EngineError {
 	BadSealFieldSize(OutOfBounds<usize>),
 	/// Validation proof insufficient.
 	InsufficientProof(String),
+	/// Engine parameters are invalid.
+	InvalidParams(String),
 }
 
 impl fmt::Display for EngineError {
@@ -84,6 +86,7 @@ impl fmt::Display for EngineError {
 			UnexpectedMessage => "This Engine should not be fed messages.".into(),
 			BadSealFieldSize(ref oob) => format!("Seal field has an unexpected length: {}", oob),
 			InsufficientProof(ref msg) => format!("Insufficient validation proof: {}", msg),
+			InvalidParams(ref msg) => format!("Invalid engine parameters: {}", msg),
 		};
 
 		f.write_fmt(format_args!("Engine error ({})", msg))
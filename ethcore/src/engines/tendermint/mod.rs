@@ -257,7 +257,7 @@ impl Tendermint {
 	fn view_proposer(&self, bh: &H256, height: Height, view: View) -> Address {
 		let proposer_nonce = height + view;
 		trace!(target: "engine", "Proposer nonce: {}", proposer_nonce);
-		self.validators.get(bh, proposer_nonce)
+		self.validators.get(bh, proposer_nonce as u64)
 	}
 
 	/// Check if address is a proposer for given view.
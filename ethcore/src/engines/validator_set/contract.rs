@@ -84,7 +84,7 @@ impl ValidatorSet for ValidatorContract {
 		self.validators.contains_with_caller(bh, address, caller)
 	}
 
-	fn get_with_caller(&self, bh: &H256, nonce: usize, caller: &Call) -> Address {
+	fn get_with_caller(&self, bh: &H256, nonce: u64, caller: &Call) -> Address {
 		self.validators.get_with_caller(bh, nonce, caller)
 	}
 
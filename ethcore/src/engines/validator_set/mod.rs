@@ -65,7 +65,7 @@ pub trait ValidatorSet: Send + Sync {
 		self.contains_with_caller(parent, address, &*default)
 	}
 	/// Draws an validator nonce modulo number of validators.
-	fn get(&self, parent: &H256, nonce: usize) -> Address {
+	fn get(&self, parent: &H256, nonce: u64) -> Address {
 		let default = self.default_caller(BlockId::Hash(*parent));
 		self.get_with_caller(parent, nonce, &*default)
 	}
@@ -109,7 +109,7 @@ pub trait ValidatorSet: Send + Sync {
 
 	/// Draws an validator nonce modulo number of validators.
 	///
-	fn get_with_caller(&self, parent_block_hash: &H256, nonce: usize, caller: &Call) -> Address;
+	fn get_with_caller(&self, parent_block_hash: &H256, nonce: u64, caller: &Call) -> Address;
 
 	/// Returns the current number of validators.
 	fn count_with_caller(&self, parent_block_hash: &H256, caller: &Call) -> usize;
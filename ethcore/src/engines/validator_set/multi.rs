@@ -122,7 +122,7 @@ impl ValidatorSet for Multi {
 			.map_or(false, |set| set.contains_with_caller(bh, address, caller))
 	}
 
-	fn get_with_caller(&self, bh: &H256, nonce: usize, caller: &Call) -> Address {
+	fn get_with_caller(&self, bh: &H256, nonce: u64, caller: &Call) -> Address {
 		self.correct_set(BlockId::Hash(*bh))
 			.map_or_else(Default::default, |set| set.get_with_caller(bh, nonce, caller))
 	}
@@ -231,7 +231,7 @@ impl ValidatorSet for ValidatorSafeContract {
 				 }))
 	}
 
-	fn get_with_caller(&self, block_hash: &H256, nonce: usize, caller: &Call) -> Address {
+	fn get_with_caller(&self, block_hash: &H256, nonce: u64, caller: &Call) -> Address {
 		let mut guard = self.validators.write();
 		let maybe_existing = guard
 			.get_mut(block_hash)
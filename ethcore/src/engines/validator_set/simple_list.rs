@@ -71,9 +71,9 @@ impl ValidatorSet for SimpleList {
 		self.validators.contains(address)
 	}
 
-	fn get_with_caller(&self, _bh: &H256, nonce: usize, _: &Call) -> Address {
+	fn get_with_caller(&self, _bh: &H256, nonce: u64, _: &Call) -> Address {
 		let validator_n = self.validators.len();
-		self.validators.get(nonce % validator_n).expect("There are validator_n authorities; taking number modulo validator_n gives number in validator_n range; qed").clone()
+		self.validators.get((nonce % validator_n as u64) as usize).expect("There are validator_n authorities; taking number modulo validator_n gives number in validator_n range; qed").clone()
 	}
 
 	fn count_with_caller(&self, _bh: &H256, _: &Call) -> usize {
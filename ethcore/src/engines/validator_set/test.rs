@@ -70,7 +70,7 @@ impl ValidatorSet for TestSet {
 		self.validator.contains(bh, address)
 	}
 
-	fn get_with_caller(&self, bh: &H256, nonce: usize, _: &Call) -> Address {
+	fn get_with_caller(&self, bh: &H256, nonce: u64, _: &Call) -> Address {
 		self.validator.get(bh, nonce)
 	}
 
@@ -175,6 +175,8 @@ struct TransactionOrder {
 	strategy: PrioritizationStrategy,
 	/// Hash to identify associated transaction
 	hash: H256,
+	/// Sender of the transaction. Usage depends on strategy.
+	sender: Address,
 	/// Origin of the transaction
 	origin: TransactionOrigin,
 	/// Penalties
@@ -193,6 +195,7 @@ impl TransactionOrder {
 			gas_factor: factor,
 			strategy: strategy,
 			hash: tx.hash(),
+			sender: tx.sender(),
 			origin: tx.origin,
 			penalties: 0,
 		}
@@ -207,22 +210,17 @@ impl TransactionOrder {
 		self.penalties = self.penalties.saturating_add(1);
 		self
 	}
-}
 
-impl Eq for TransactionOrder {}
-impl PartialEq for TransactionOrder {
-	fn eq(&self, other: &TransactionOrder) -> bool {
-		self.cmp(other) == Ordering::Equal
-	}
-}
-impl PartialOrd for TransactionOrder {
-	fn partial_cmp(&self, other: &TransactionOrder) -> Option<Ordering> {
-		Some(self.cmp(other))
+	/// Orders by sender, nonce and hash only, so that the order depends only on the transactions.
+	fn cmp_deterministic(&self, b: &TransactionOrder) -> Ordering {
+		self.sender.cmp(&b.sender)
+			.then(self.nonce_height.cmp(&b.nonce_height))
+			.then(self.hash.cmp(&b.hash))
 	}
-}
 
-impl Ord for TransactionOrder {
-	fn cmp(&self, b: &TransactionOrder) -> Ordering {
+	/// Orders by penalties, origin and nonce height, then by the strategy-specific `by_strategy`,
+	/// then by gas price and hash.
+	fn cmp_priority<F>(&self, b: &TransactionOrder, by_strategy: F) -> Ordering where F: FnOnce() -> Ordering {
 		// First check number of penalties
 		if self.penalties != b.penalties {
 			return self.penalties.cmp(&b.penalties);
@@ -238,23 +236,9 @@ impl Ord for TransactionOrder {
 			return self.nonce_height.cmp(&b.nonce_height);
 		}
 
-		match self.strategy {
-			PrioritizationStrategy::GasAndGasPrice => {
-				if self.gas != b.gas {
-					return self.gas.cmp(&b.gas);
-				}
-			},
-			PrioritizationStrategy::GasFactorAndGasPrice => {
-				// avoiding overflows
-				// (gp1 - g1) > (gp2 - g2) <=>
-				// (gp1 + g2) > (gp2 + g1)
-				let f_a = self.gas_price + b.gas_factor;
-				let f_b = b.gas_price + self.gas_factor;
-				if f_a != f_b {
-					return f_b.cmp(&f_a);
-				}
-			},
-			PrioritizationStrategy::GasPriceOnly => {},
+		let by_strategy = by_strategy();
+		if by_strategy != Ordering::Equal {
+			return by_strategy;
 		}
 
 		// Then compare gas_prices
@@ -267,6 +251,36 @@ impl Ord for TransactionOrder {
 	}
 }
 
+impl Eq for TransactionOrder {}
+impl PartialEq for TransactionOrder {
+	fn eq(&self, other: &TransactionOrder) -> bool {
+		self.cmp(other) == Ordering::Equal
+	}
+}
+impl PartialOrd for TransactionOrder {
+	fn partial_cmp(&self, other: &TransactionOrder) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for TransactionOrder {
+	fn cmp(&self, b: &TransactionOrder) -> Ordering {
+		match self.strategy {
+			PrioritizationStrategy::Deterministic => self.cmp_deterministic(b),
+			PrioritizationStrategy::GasPriceOnly => self.cmp_priority(b, || Ordering::Equal),
+			PrioritizationStrategy::GasAndGasPrice => self.cmp_priority(b, || self.gas.cmp(&b.gas)),
+			PrioritizationStrategy::GasFactorAndGasPrice => self.cmp_priority(b, || {
+				// avoiding overflows
+				// (gp1 - g1) > (gp2 - g2) <=>
+				// (gp1 + g2) > (gp2 + g1)
+				let f_a = self.gas_price + b.gas_factor;
+				let f_b = b.gas_price + self.gas_factor;
+				f_b.cmp(&f_a)
+			}),
+		}
+	}
+}
+
 /// Verified transaction
 #[derive(Debug)]
 struct VerifiedTransaction {
@@ -508,6 +522,10 @@ pub enum PrioritizationStrategy {
 	/// 1M gas tx with `gas_price=30*min` has the same priority
 	/// as 32k gas tx with `gas_price=min`
 	GasFactorAndGasPrice,
+	/// Order by sender, then nonce, then hash, disregarding gas price, origin and penalties.
+	/// Produces the same block for the same set of transactions regardless of arrival order,
+	/// which is useful for reproducible benchmarks. Not suitable for public networks.
+	Deterministic,
 }
 
 /// Reason to remove single transaction from the queue.
@@ -1855,6 +1873,43 @@ pub mod test {
 		assert_eq!(txq.top_transactions()[3].gas_price, 40.into());
 	}
 
+	#[test]
+	fn should_order_deterministically() {
+		// given
+		let mut keypairs = vec![Random.generate().unwrap(), Random.generate().unwrap(), Random.generate().unwrap()];
+		keypairs.sort_by_key(|k| k.address());
+		// The lower the sender address, the lower the gas price.
+		let tx1 = new_unsigned_tx(default_nonce(), default_gas_val(), 20.into()).sign(keypairs[0].secret(), None);
+		let tx2 = new_unsigned_tx(default_nonce(), default_gas_val(), 30.into()).sign(keypairs[1].secret(), None);
+		let tx3 = new_unsigned_tx(default_nonce(), default_gas_val(), 40.into()).sign(keypairs[2].secret(), None);
+		let (tx4, tx5) = new_tx_pair_default(1.into(), 0.into());
+		let mut expected = vec![tx1.clone(), tx2.clone(), tx3.clone(), tx4.clone(), tx5.clone()];
+		expected.sort_by(|a, b| a.sender().cmp(&b.sender()).then(a.nonce.cmp(&b.nonce)));
+		let mut txq1 = TransactionQueue::new(PrioritizationStrategy::Deterministic);
+		let mut txq2 = TransactionQueue::new(PrioritizationStrategy::Deterministic);
+
+		// when
+		txq1.add(tx1.clone(), TransactionOrigin::External, 0, None, &default_tx_provider()).unwrap();
+		txq1.add(tx2.clone(), TransactionOrigin::Local, 0, None, &default_tx_provider()).unwrap();
+		txq1.add(tx3.clone(), TransactionOrigin::External, 0, None, &default_tx_provider()).unwrap();
+		txq1.add(tx4.clone(), TransactionOrigin::External, 0, None, &default_tx_provider()).unwrap();
+		txq1.add(tx5.clone(), TransactionOrigin::External, 0, None, &default_tx_provider()).unwrap();
+		txq2.add(tx5, TransactionOrigin::External, 0, None, &default_tx_provider()).unwrap();
+		txq2.add(tx3.clone(), TransactionOrigin::Local, 0, None, &default_tx_provider()).unwrap();
+		txq2.add(tx4, TransactionOrigin::External, 0, None, &default_tx_provider()).unwrap();
+		txq2.add(tx2.clone(), TransactionOrigin::External, 0, None, &default_tx_provider()).unwrap();
+		txq2.add(tx1.clone(), TransactionOrigin::External, 0, None, &default_tx_provider()).unwrap();
+
+		// then
+		let top = txq1.top_transactions();
+		assert_eq!(top, expected);
+		assert_eq!(txq2.top_transactions(), expected);
+		// Gas price is ignored: cheaper transactions from lower senders come first.
+		let position = |tx: &SignedTransaction| top.iter().position(|t| t == tx).unwrap();
+		assert!(position(&tx1) < position(&tx2));
+		assert!(position(&tx2) < position(&tx3));
+	}
+
 	#[test]
 	fn gas_limit_should_never_overflow() {
 		// given
@@ -153,7 +153,7 @@ fn load_from(s: ethjson::spec::Spec) -> Result<Spec, Error> {
 
 	let mut s = Spec {
 		name: s.name.clone().into(),
-		engine: Spec::engine(s.engine, params, builtins),
+		engine: Spec::engine(s.engine, params, builtins)?,
 		data_dir: s.data_dir.unwrap_or(s.name).into(),
 		nodes: s.nodes.unwrap_or_else(Vec::new),
 		parent_hash: g.parent_hash,
@@ -189,15 +189,16 @@ macro_rules! load_bundled {
 impl Spec {
 	/// Convert engine spec into a arc'd Engine of the right underlying type.
 	/// TODO avoid this hard-coded nastiness - use dynamic-linked plugin framework instead.
-	fn engine(engine_spec: ethjson::spec::Engine, params: CommonParams, builtins: BTreeMap<Address, Builtin>) -> Arc<Engine> {
-		match engine_spec {
+	fn engine(engine_spec: ethjson::spec::Engine, params: CommonParams, builtins: BTreeMap<Address, Builtin>) -> Result<Arc<Engine>, Error> {
+		let engine: Arc<Engine> = match engine_spec {
 			ethjson::spec::Engine::Null => Arc::new(NullEngine::new(params, builtins)),
 			ethjson::spec::Engine::InstantSeal(instant) => Arc::new(InstantSeal::new(params, instant.params.registrar.map_or_else(Address::new, Into::into), builtins)),
 			ethjson::spec::Engine::Ethash(ethash) => Arc::new(ethereum::Ethash::new(params, From::from(ethash.params), builtins)),
 			ethjson::spec::Engine::BasicAuthority(basic_authority) => Arc::new(BasicAuthority::new(params, From::from(basic_authority.params), builtins)),
-			ethjson::spec::Engine::AuthorityRound(authority_round) => AuthorityRound::new(params, From::from(authority_round.params), builtins).expect("Failed to start AuthorityRound consensus engine."),
+			ethjson::spec::Engine::AuthorityRound(authority_round) => AuthorityRound::new(params, From::from(authority_round.params), builtins)?,
 			ethjson::spec::Engine::Tendermint(tendermint) => Tendermint::new(params, From::from(tendermint.params), builtins).expect("Failed to start the Tendermint consensus engine."),
-		}
+		};
+		Ok(engine)
 	}
 
 	// given a pre-constructor state, run all the given constructors and produce a new state and state root.
@@ -443,6 +444,14 @@ mod tests {
 		assert!(Spec::load(&[] as &[u8]).is_err());
 	}
 
+	#[test]
+	fn rejects_zero_step_duration() {
+		let json = String::from_utf8(include_bytes!("../../res/authority_round.json").to_vec()).unwrap();
+		assert!(Spec::load(json.as_bytes()).is_ok());
+		let json = json.replace("\"stepDuration\": 1", "\"stepDuration\": 0");
+		assert!(Spec::load(json.as_bytes()).is_err());
+	}
+
 	#[test]
 	fn test_chain() {
 		let test_spec = Spec::new_test();
@@ -294,6 +294,26 @@ fn change_history_size() {
 	assert_eq!(client.state().balance(&address).unwrap(), 100.into());
 }
 
+#[test]
+fn returns_balances_at_block() {
+	let client = generate_dummy_client(0);
+	let address = Address::random();
+	let other = Address::random();
+	let test_spec = Spec::new_test();
+	for _ in 0..3 {
+		let mut b = client.prepare_open_block(Address::default(), (3141562.into(), 31415620.into()), vec![]);
+		b.block_mut().fields_mut().state.add_balance(&address, &5.into(), CleanupMode::NoEmpty).unwrap();
+		b.block_mut().fields_mut().state.commit().unwrap();
+		let b = b.close_and_lock().seal(&*test_spec.engine, vec![]).unwrap();
+		client.import_sealed_block(b).unwrap();
+	}
+
+	assert_eq!(client.balances(vec![address, other], BlockId::Number(2)), Some(vec![10.into(), 0.into()]));
+	assert_eq!(client.balances(vec![other, address], BlockId::Latest), Some(vec![0.into(), 15.into()]));
+	assert_eq!(client.balances(vec![], BlockId::Latest), Some(vec![]));
+	assert_eq!(client.balances(vec![address], BlockId::Number(10)), None);
+}
+
 #[test]
 fn does_not_propagate_delayed_transactions() {
 	let key = KeyPair::from_secret("test".sha3().into()).unwrap();
@@ -26,9 +26,12 @@ pub struct AuthorityRoundParams {
 	/// Gas limit divisor.
 	#[serde(rename="gasLimitBoundDivisor")]
 	pub gas_limit_bound_divisor: Uint,
-	/// Block duration.
+	/// Block duration, in seconds.
 	#[serde(rename="stepDuration")]
-	pub step_duration: Uint,
+	pub step_duration: Option<Uint>,
+	/// Block duration, in milliseconds. Takes precedence over `stepDuration`.
+	#[serde(rename="stepDurationMs")]
+	pub step_duration_ms: Option<Uint>,
 	/// Valid authorities
 	pub validators: ValidatorSet,
 	/// Block reward.
@@ -86,11 +89,29 @@ mod tests {
 
 		let deserialized: AuthorityRound = serde_json::from_str(s).unwrap();
 		assert_eq!(deserialized.params.gas_limit_bound_divisor, Uint(U256::from(0x0400)));
-		assert_eq!(deserialized.params.step_duration, Uint(U256::from(0x02)));
+		assert_eq!(deserialized.params.step_duration, Some(Uint(U256::from(0x02))));
+		assert!(deserialized.params.step_duration_ms.is_none());
 		assert_eq!(deserialized.params.validators, ValidatorSet::List(vec![Address(H160::from("0xc6d9d2cd449a754c494264e1809c50e34d64562b"))]));
 		assert_eq!(deserialized.params.block_reward, Some(Uint(U256::from(0x50))));
 		assert!(deserialized.params.registrar.is_none());
 		assert_eq!(deserialized.params.start_step, Some(Uint(U256::from(24))));
 		assert_eq!(deserialized.params.eip155_transition, Some(Uint(U256::from(0x42))));
 	}
+
+	#[test]
+	fn authority_round_deserialization_with_millis() {
+		let s = r#"{
+			"params": {
+				"gasLimitBoundDivisor": "0x0400",
+				"stepDurationMs": 250,
+				"validators": {
+					"list" : ["0xc6d9d2cd449a754c494264e1809c50e34d64562b"]
+				}
+			}
+		}"#;
+
+		let deserialized: AuthorityRound = serde_json::from_str(s).unwrap();
+		assert!(deserialized.params.step_duration.is_none());
+		assert_eq!(deserialized.params.step_duration_ms, Some(Uint(U256::from(250))));
+	}
 }
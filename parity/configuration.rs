@@ -1321,6 +1321,7 @@ mod tests {
 		let conf1 = parse(&["parity", "--tx-queue-strategy", "gas_factor"]);
 		let conf2 = parse(&["parity", "--tx-queue-strategy", "gas_price"]);
 		let conf3 = parse(&["parity", "--tx-queue-strategy", "gas"]);
+		let conf4 = parse(&["parity", "--tx-queue-strategy", "deterministic"]);
 
 		// then
 		let min_period = conf0.args.flag_reseal_min_period;
@@ -1331,6 +1332,8 @@ mod tests {
 		assert_eq!(conf2.miner_options(min_period).unwrap(), mining_options);
 		mining_options.tx_queue_strategy = PrioritizationStrategy::GasAndGasPrice;
 		assert_eq!(conf3.miner_options(min_period).unwrap(), mining_options);
+		mining_options.tx_queue_strategy = PrioritizationStrategy::Deterministic;
+		assert_eq!(conf4.miner_options(min_period).unwrap(), mining_options);
 	}
 
 	#[test]
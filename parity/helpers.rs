@@ -107,6 +107,7 @@ pub fn to_queue_strategy(s: &str) -> Result<PrioritizationStrategy, String> {
 		"gas" => Ok(PrioritizationStrategy::GasAndGasPrice),
 		"gas_price" => Ok(PrioritizationStrategy::GasPriceOnly),
 		"gas_factor" => Ok(PrioritizationStrategy::GasFactorAndGasPrice),
+		"deterministic" => Ok(PrioritizationStrategy::Deterministic),
 		other => Err(format!("Invalid queue strategy: {}", other)),
 	}
 }